@@ -60,6 +60,225 @@ fn give_ownership(some_string: String) -> String {
     some_string // since there is no semicolon (;) this is an expression and returns a value
 }
 
+fn makes_copy(n: i32) {
+    println!("{}", n);
+
+    // n goes out of scope here, but since i32 implements Copy there's nothing special to clean up
+}
+
+fn copy_semantics() {
+    // simple types of fixed length that are known entirely at compile time, and fit on the stack, implement
+    // the Copy trait. assigning or passing these types copies the value instead of moving it
+
+    let x = 5;
+    let y = x;
+
+    // both x and y are valid here, because i32 is Copy: y got a bitwise copy of x rather than taking ownership
+    println!("x = {x}, y = {y}");
+
+    let b = true;
+    let c = b;
+
+    println!("b = {b}, c = {c}");
+
+    let ch1 = 'a';
+    let ch2 = ch1;
+
+    println!("ch1 = {ch1}, ch2 = {ch2}");
+
+    // a tuple is Copy as long as every type it contains is also Copy, so a fixed tuple of simple types qualifies
+    let t1 = (1, 2.0);
+    let t2 = t1;
+
+    println!("t1 = {t1:?}, t2 = {t2:?}");
+
+    // passing x to a function behaves the same way as the assignment above
+    makes_copy(x);
+
+    // x is still valid here, because makes_copy only received a copy of it
+    println!("x is still usable: {x}");
+
+    // a String does not implement Copy, so assigning it moves ownership instead of copying
+    let s1 = String::from("hello");
+    let s2 = s1;
+
+    // uncommenting the line below would fail to compile with "value borrowed here after move", because s1's
+    // heap data was moved into s2, not copied
+    // println!("{s1}");
+
+    println!("{s2}");
+
+    // a tuple that contains a String is not Copy, even though it also contains an i32, because every field
+    // must be Copy for the tuple as a whole to qualify
+    let t3 = (1, String::from("world"));
+    let t4 = t3;
+
+    // uncommenting the line below would fail to compile for the same reason as s1 above: t3.1 was moved into t4
+    // println!("{t3:?}");
+
+    println!("{t4:?}");
+}
+
+struct Resource {
+    name: String,
+}
+
+impl Drop for Resource {
+    fn drop(&mut self) {
+        // this runs automatically when the Resource goes out of scope, making the normally invisible
+        // "owner goes out of scope, value is dropped" rule observable
+        println!("dropping resource: {}", self.name);
+    }
+}
+
+fn drop_demo() {
+    // resource_a is created first, so per the usual LIFO scoping rules it will be dropped last
+    let resource_a = Resource {
+        name: String::from("a"),
+    };
+
+    {
+        let resource_b = Resource {
+            name: String::from("b"),
+        };
+
+        // resource_b goes out of scope at the end of this block and is dropped here, before resource_a
+        println!("end of inner block, resource_b still in scope: {}", resource_b.name);
+    }
+
+    {
+        let resource_c = Resource {
+            name: String::from("c"),
+        };
+
+        println!("end of inner block, resource_c still in scope: {}", resource_c.name);
+    }
+
+    // std::mem::drop lets us free a value early, rather than waiting for it to go out of scope naturally
+    let resource_d = Resource {
+        name: String::from("d"),
+    };
+
+    println!("about to drop resource_d early");
+    std::mem::drop(resource_d);
+    println!("resource_d has already been dropped by the time this prints");
+
+    // resource_a is still in scope and is dropped last, when drop_demo returns
+    println!("resource_a still in scope: {}", resource_a.name);
+}
+
+fn calculate_length(s: &String) -> usize {
+    // s is a reference to a String, so we're borrowing the value rather than taking ownership of it
+    s.len()
+
+    // s goes out of scope here, but because it doesn't have ownership of what it refers to, nothing is dropped
+}
+
+fn change(s: &mut String) {
+    // a mutable reference lets us modify a borrowed value without taking ownership of it
+    s.push_str(", from world!");
+}
+
+fn references() {
+    // references let a function use a value without taking ownership of it, which avoids the tedious
+    // "pass it in, then return it back out" dance that give_ownership/take_ownership require
+
+    let s1 = String::from("hello");
+
+    // &s1 creates a reference that points to s1 without taking ownership of it. this is called borrowing
+    let len = calculate_length(&s1);
+
+    // s1 is still valid here because calculate_length only ever borrowed it
+    println!("the length of '{s1}' is {len}");
+
+    // references are immutable by default, just like variables, so we need a mutable reference to change
+    // a borrowed value
+    let mut s2 = String::from("hello");
+
+    change(&mut s2);
+
+    println!("{s2}");
+
+    // the big restriction: if you have a mutable reference to a value, you can have no other references
+    // (mutable or immutable) to that value at the same time. this prevents data races at compile time
+    let r1 = &s2;
+    let r2 = &s2;
+
+    // multiple immutable references are fine, since none of them can write to s2
+    println!("{r1} and {r2}");
+
+    // r1 and r2 are no longer used after the println! above, so their scope ends here (non-lexical lifetimes)
+    // which means we're now free to take a mutable reference
+    let r3 = &mut s2;
+
+    println!("{r3}");
+
+    // uncommenting the two lines below would fail to compile with "cannot borrow `s2` as immutable because
+    // it is also borrowed as mutable", because r3 is still in scope at that point
+    // let r4 = &s2;
+    // println!("{r3}, {r4}");
+}
+
+// fn dangle() -> &String {
+//     // this function tries to return a reference to a String created inside it
+//     let s = String::from("hello");
+//
+//     &s
+//
+//     // s goes out of scope and is dropped here, so the reference we tried to return would point to
+//     // memory that's already been freed. rust's borrow checker rejects this with "missing lifetime
+//     // specifier" / "this function's return type contains a borrowed value, but there is no value for
+//     // it to be borrowed from", because it won't let us return a dangling reference
+// }
+
+fn first_word(s: &str) -> &str {
+    // converting to bytes lets us scan for the space character, since str doesn't index by character directly
+    let bytes = s.as_bytes();
+
+    // iter().enumerate() gives us (index, &byte) pairs to walk through the string one byte at a time
+    for (i, &item) in bytes.iter().enumerate() {
+        // b' ' is a byte literal for the space character, so we're comparing byte to byte
+        if item == b' ' {
+            // we've found the first space, so the slice from the start up to (but not including) it is the first word
+            return &s[0..i];
+        }
+    }
+
+    // no space was found, so the whole string is one word
+    s
+}
+
+fn slices() {
+    // a string slice is a reference to part of a String, so like any other reference it doesn't take ownership
+
+    let s = String::from("hello world");
+
+    // [0..5] and [6..11] are slices that borrow from s rather than copying the bytes out
+    let hello = &s[0..5];
+    let world = &s[6..11];
+
+    println!("{hello} {world}");
+
+    // first_word returns a slice borrowed from s, tying the lifetime of the returned &str to s itself
+    let word = first_word(&s);
+
+    println!("the first word is: {word}");
+
+    // uncommenting the line below would fail to compile with "cannot borrow `s` as mutable because it is
+    // also borrowed as immutable", because word is a reference into s and s.clear() needs a mutable borrow
+    // s.clear();
+
+    println!("{word}");
+
+    // slices aren't just for String: arrays can be sliced too
+    let arr = [1, 2, 3, 4, 5];
+
+    // this slice has the type &[i32] and borrows elements 1 and 2 (index 3 is excluded) from arr
+    let arr_slice = &arr[1..3];
+
+    println!("{arr_slice:?}");
+}
+
 fn main() {
     // using string literal function to cover the basics of scope blocks
     string_literals();
@@ -87,8 +306,16 @@ fn main() {
     let s3 = give_ownership(s2);
 
     println!("{s3}");
-}
 
+    // references let us use a value without taking ownership of it, avoiding the return-and-rebind dance above
+    references();
+
+    // slices let us borrow part of a collection, rather than the whole thing
+    slices();
+
+    // contrasting Copy stack types against moved heap-owning types makes the Copy boundary concrete
+    copy_semantics();
 
-// the above method of passing data around and having to return from a function and bind to a new variable is quite tedious
-// so there is a further method of transferring ownership that uses references and the concept of borrowing
\ No newline at end of file
+    // a custom Drop implementation makes scope-based deallocation order observable at runtime
+    drop_demo();
+}
\ No newline at end of file